@@ -4,6 +4,7 @@ use crate::idx::trees::vector::SharedVector;
 use crate::key::error::KeyCategory;
 use crate::sql::idiom::Idiom;
 use crate::sql::index::Distance;
+use crate::sql::number::Number;
 use crate::sql::thing::Thing;
 use crate::sql::value::Value;
 use crate::syn::error::RenderedError as RenderedParserError;
@@ -234,6 +235,10 @@ pub enum Error {
 		dist: f64,
 	},
 
+	/// The order of a Minkowski distance is invalid
+	#[error("Invalid Minkowski order: {0}. The order must be a positive number.")]
+	InvalidMinkowskiOrder(Number),
+
 	/// The size of the vector is incorrect
 	#[error("The vector element ({current}) is not a number.")]
 	InvalidVectorType {
@@ -40,6 +40,7 @@ impl MTreeIndex {
 		p: &MTreeParams,
 		tt: TransactionType,
 	) -> Result<Self, Error> {
+		p.distance.validate()?;
 		let doc_ids = Arc::new(RwLock::new(
 			DocIds::new(ixs, tx, tt, ikb.clone(), p.doc_ids_order, p.doc_ids_cache).await?,
 		));
@@ -312,6 +313,13 @@ impl MTree {
 		debug!("knn_search - v: {:?} - k: {}", v, k);
 		let mut queue = BinaryHeap::new();
 		let mut res = KnnResultBuilder::new(k);
+		// There is nothing to collect, we don't need to visit the tree
+		if k == 0 {
+			return Ok(res.build(
+				#[cfg(debug_assertions)]
+				HashMap::new(),
+			));
+		}
 		if let Some(root_id) = self.state.root {
 			queue.push(PriorityNode(0.0, root_id));
 		}
@@ -1606,6 +1614,7 @@ impl VersionedSerdeState for MState {}
 mod tests {
 	use rand::prelude::StdRng;
 	use rand::{Rng, SeedableRng};
+	use roaring::RoaringTreemap;
 	use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 	use std::sync::Arc;
 
@@ -1614,7 +1623,7 @@ mod tests {
 
 	use crate::idx::docids::DocId;
 	use crate::idx::trees::mtree::{
-		InternalMap, MState, MTree, MTreeNode, MTreeStore, ObjectProperties,
+		InternalMap, KnnResultBuilder, MState, MTree, MTreeNode, MTreeStore, ObjectProperties,
 	};
 	use crate::idx::trees::store::{NodeId, TreeNodeProvider, TreeStore};
 	use crate::idx::trees::vector::{SharedVector, Vector};
@@ -2030,6 +2039,42 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_knn_result_builder_partial_docs() {
+		let mut b = KnnResultBuilder::new(3);
+		b.add(0.5, &RoaringTreemap::from_iter([1]));
+		// This object brings more docs than the remaining slots
+		assert!(b.check_add(1.0));
+		b.add(1.0, &RoaringTreemap::from_iter([2, 3, 4, 5, 6]));
+		// The builder is full, farther objects are rejected
+		assert!(!b.check_add(2.0));
+		// A closer object is still accepted, the shared docs are not evicted
+		assert!(b.check_add(0.2));
+		b.add(0.2, &RoaringTreemap::from_iter([7]));
+		let res = b.build(
+			#[cfg(debug_assertions)]
+			HashMap::new(),
+		);
+		check_knn(&res.docs, vec![7, 1, 2]);
+	}
+
+	#[test(tokio::test)]
+	async fn test_mtree_knn_zero() -> Result<(), Error> {
+		const CACHE_SIZE: usize = 20;
+
+		let mut t = MTree::new(MState::new(3), Distance::Euclidean);
+		let ds = Datastore::new("memory").await?;
+		let collection = TestCollection::new_unique(10, VectorType::F64, 2);
+		insert_collection_one_by_one(&ds, &mut t, &collection, CACHE_SIZE).await?;
+
+		let (mut st, mut tx) = new_operation(&ds, &t, TransactionType::Read, CACHE_SIZE).await;
+		let res = t.knn_search(&mut tx, &mut st, &collection.as_ref()[0].1, 0).await?;
+		check_knn(&res.docs, vec![]);
+		#[cfg(debug_assertions)]
+		assert_eq!(res.visited_nodes.len(), 0);
+		Ok(())
+	}
+
 	async fn insert_collection_one_by_one(
 		ds: &Datastore,
 		t: &mut MTree,
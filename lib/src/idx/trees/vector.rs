@@ -1,5 +1,5 @@
 use crate::err::Error;
-use crate::sql::index::VectorType;
+use crate::sql::index::{Distance, VectorType};
 use crate::sql::Number;
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
@@ -158,15 +158,24 @@ impl Vector {
 				.map(|(a, b)| (*a as f64 - *b as f64).powi(2))
 				.sum::<f64>()
 				.sqrt()),
-			(Vector::I64(a), Vector::I64(b)) => {
-				Ok((a.iter().zip(b.iter()).map(|(a, b)| (a - b).pow(2)).sum::<i64>() as f64).sqrt())
-			}
-			(Vector::I32(a), Vector::I32(b)) => {
-				Ok((a.iter().zip(b.iter()).map(|(a, b)| (a - b).pow(2)).sum::<i32>() as f64).sqrt())
-			}
-			(Vector::I16(a), Vector::I16(b)) => {
-				Ok((a.iter().zip(b.iter()).map(|(a, b)| (a - b).pow(2)).sum::<i16>() as f64).sqrt())
-			}
+			(Vector::I64(a), Vector::I64(b)) => Ok(a
+				.iter()
+				.zip(b.iter())
+				.map(|(a, b)| (*a as f64 - *b as f64).powi(2))
+				.sum::<f64>()
+				.sqrt()),
+			(Vector::I32(a), Vector::I32(b)) => Ok(a
+				.iter()
+				.zip(b.iter())
+				.map(|(a, b)| (*a as f64 - *b as f64).powi(2))
+				.sum::<f64>()
+				.sqrt()),
+			(Vector::I16(a), Vector::I16(b)) => Ok(a
+				.iter()
+				.zip(b.iter())
+				.map(|(a, b)| (*a as f64 - *b as f64).powi(2))
+				.sum::<f64>()
+				.sqrt()),
 			_ => Err(Error::Unreachable("Vector::euclidean_distance")),
 		}
 	}
@@ -181,47 +190,172 @@ impl Vector {
 				Ok(a.iter().zip(b.iter()).map(|(a, b)| (*a as f64 - *b as f64).abs()).sum::<f64>())
 			}
 			(Vector::I64(a), Vector::I64(b)) => {
-				Ok(a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum::<i64>() as f64)
+				Ok(a.iter().zip(b.iter()).map(|(a, b)| (*a as f64 - *b as f64).abs()).sum::<f64>())
 			}
 			(Vector::I32(a), Vector::I32(b)) => {
-				Ok(a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum::<i32>() as f64)
+				Ok(a.iter().zip(b.iter()).map(|(a, b)| (*a as f64 - *b as f64).abs()).sum::<f64>())
 			}
 			(Vector::I16(a), Vector::I16(b)) => {
-				Ok(a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum::<i16>() as f64)
+				Ok(a.iter().zip(b.iter()).map(|(a, b)| (*a as f64 - *b as f64).abs()).sum::<f64>())
 			}
 			_ => Err(Error::Unreachable("Vector::manhattan_distance")),
 		}
 	}
-	pub(super) fn minkowski_distance(&self, other: &Self, order: &Number) -> Result<f64, Error> {
-		Self::check_same_dimension("vector::distance::minkowski", self, other)?;
-		let dist = match (self, other) {
-			(Vector::F64(a), Vector::F64(b)) => a
-				.iter()
-				.zip(b.iter())
-				.map(|(a, b)| (a - b).abs().powf(order.to_float()))
-				.sum::<f64>(),
-			(Vector::F32(a), Vector::F32(b)) => a
+	fn chebyshev_distance(&self, other: &Self) -> Result<f64, Error> {
+		Self::check_same_dimension("vector::distance::chebyshev", self, other)?;
+		match (self, other) {
+			(Vector::F64(a), Vector::F64(b)) => {
+				Ok(a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max))
+			}
+			(Vector::F32(a), Vector::F32(b)) => Ok(a
 				.iter()
 				.zip(b.iter())
-				.map(|(a, b)| (a - b).abs().powf(order.to_float() as f32))
-				.sum::<f32>() as f64,
-			(Vector::I64(a), Vector::I64(b)) => a
+				.map(|(a, b)| (*a as f64 - *b as f64).abs())
+				.fold(0.0, f64::max)),
+			(Vector::I64(a), Vector::I64(b)) => Ok(a
 				.iter()
 				.zip(b.iter())
-				.map(|(a, b)| (a - b).abs().pow(order.to_int() as u32))
-				.sum::<i64>() as f64,
-			(Vector::I32(a), Vector::I32(b)) => a
+				.map(|(a, b)| (*a as f64 - *b as f64).abs())
+				.fold(0.0, f64::max)),
+			(Vector::I32(a), Vector::I32(b)) => Ok(a
 				.iter()
 				.zip(b.iter())
-				.map(|(a, b)| (a - b).abs().pow(order.to_int() as u32))
-				.sum::<i32>() as f64,
-			(Vector::I16(a), Vector::I16(b)) => a
+				.map(|(a, b)| (*a as f64 - *b as f64).abs())
+				.fold(0.0, f64::max)),
+			(Vector::I16(a), Vector::I16(b)) => Ok(a
 				.iter()
 				.zip(b.iter())
-				.map(|(a, b)| (a - b).abs().pow(order.to_int() as u32))
-				.sum::<i16>() as f64,
+				.map(|(a, b)| (*a as f64 - *b as f64).abs())
+				.fold(0.0, f64::max)),
+			_ => Err(Error::Unreachable("Vector::chebyshev_distance")),
+		}
+	}
+
+	pub(super) fn minkowski_distance(&self, other: &Self, order: &Number) -> Result<f64, Error> {
+		Self::check_same_dimension("vector::distance::minkowski", self, other)?;
+		// The order is validated when the index is defined, this is a backstop
+		Distance::check_minkowski_order(order)?;
+		let p = order.to_float();
+		if p == f64::INFINITY {
+			return self.chebyshev_distance(other);
+		}
+		// Fast paths: the usual orders are delegated to their dedicated implementations
+		if p == 1.0 {
+			return self.manhattan_distance(other);
+		}
+		if p == 2.0 {
+			return self.euclidean_distance(other);
+		}
+		// For other small integer orders, powi is much cheaper than powf
+		let pi = if p.fract() == 0.0 && p <= 16.0 {
+			Some(p as i32)
+		} else {
+			None
+		};
+		let pow = |d: f64| match pi {
+			Some(pi) => d.powi(pi),
+			None => d.powf(p),
+		};
+		// F32 and integers are converted to f64 before the subtraction (like the other distances),
+		// so the integer subtraction can't overflow
+		let dist = match (self, other) {
+			(Vector::F64(a), Vector::F64(b)) => {
+				a.iter().zip(b.iter()).map(|(a, b)| pow((a - b).abs())).sum::<f64>()
+			}
+			(Vector::F32(a), Vector::F32(b)) => {
+				a.iter().zip(b.iter()).map(|(a, b)| pow((*a as f64 - *b as f64).abs())).sum::<f64>()
+			}
+			(Vector::I64(a), Vector::I64(b)) => {
+				a.iter().zip(b.iter()).map(|(a, b)| pow((*a as f64 - *b as f64).abs())).sum::<f64>()
+			}
+			(Vector::I32(a), Vector::I32(b)) => {
+				a.iter().zip(b.iter()).map(|(a, b)| pow((*a as f64 - *b as f64).abs())).sum::<f64>()
+			}
+			(Vector::I16(a), Vector::I16(b)) => {
+				a.iter().zip(b.iter()).map(|(a, b)| pow((*a as f64 - *b as f64).abs())).sum::<f64>()
+			}
 			_ => return Err(Error::Unreachable("Vector::minkowski_distance")),
 		};
-		Ok(dist.powf(1.0 / order.to_float()))
+		Ok(dist.powf(1.0 / p))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::err::Error;
+	use crate::idx::trees::vector::Vector;
+	use crate::sql::Number;
+
+	#[test]
+	fn test_minkowski_integer_orders() {
+		let a = Vector::F64(vec![0.1, -2.5, 3.7, 1e-3]);
+		let b = Vector::F64(vec![4.2, 0.0, -1.3, 7.5]);
+		assert_eq!(
+			a.minkowski_distance(&b, &Number::Int(1)).unwrap(),
+			a.manhattan_distance(&b).unwrap()
+		);
+		assert_eq!(
+			a.minkowski_distance(&b, &Number::Int(2)).unwrap(),
+			a.euclidean_distance(&b).unwrap()
+		);
+		assert_eq!(
+			a.minkowski_distance(&b, &Number::Float(2.0)).unwrap(),
+			a.euclidean_distance(&b).unwrap()
+		);
+		let expected =
+			[4.1f64, 2.5, 5.0, 7.499].iter().map(|d| d.powf(3.0)).sum::<f64>().powf(1.0 / 3.0);
+		let dist = a.minkowski_distance(&b, &Number::Int(3)).unwrap();
+		assert!((dist - expected).abs() < 1e-9, "{dist} - {expected}");
+		// Large integer orders still use powf
+		let expected =
+			[4.1f64, 2.5, 5.0, 7.499].iter().map(|d| d.powf(20.0)).sum::<f64>().powf(1.0 / 20.0);
+		let dist = a.minkowski_distance(&b, &Number::Int(20)).unwrap();
+		assert!((dist - expected).abs() < 1e-9, "{dist} - {expected}");
+		//
+		let a = Vector::F32(vec![1.0, 2.0, 3.0]);
+		let b = Vector::F32(vec![4.0, 6.0, 3.5]);
+		assert_eq!(
+			a.minkowski_distance(&b, &Number::Int(2)).unwrap(),
+			a.euclidean_distance(&b).unwrap()
+		);
+		//
+		let a = Vector::I64(vec![1, 2, 3]);
+		let b = Vector::I64(vec![4, 6, 8]);
+		assert_eq!(
+			a.minkowski_distance(&b, &Number::Int(2)).unwrap(),
+			a.euclidean_distance(&b).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_integer_distances_do_not_overflow() {
+		let a = Vector::I16(vec![i16::MIN]);
+		let b = Vector::I16(vec![i16::MAX]);
+		assert_eq!(a.manhattan_distance(&b).unwrap(), 65535.0);
+		assert_eq!(a.euclidean_distance(&b).unwrap(), 65535.0);
+		assert_eq!(a.minkowski_distance(&b, &Number::Int(3)).unwrap().round(), 65535.0);
+		//
+		let a = Vector::I32(vec![i32::MIN, 0]);
+		let b = Vector::I32(vec![i32::MAX, 0]);
+		assert_eq!(a.manhattan_distance(&b).unwrap(), 4294967295.0);
+		assert_eq!(a.euclidean_distance(&b).unwrap(), 4294967295.0);
+	}
+
+	#[test]
+	fn test_minkowski_order_validation() {
+		let a = Vector::F64(vec![1.0, 2.0, 3.0]);
+		let b = Vector::F64(vec![4.0, -6.0, 3.5]);
+		for order in [Number::Int(0), Number::Int(-1), Number::Float(-0.5), Number::NAN] {
+			assert!(
+				matches!(a.minkowski_distance(&b, &order), Err(Error::InvalidMinkowskiOrder(_))),
+				"{order}"
+			);
+		}
+		assert_eq!(a.minkowski_distance(&b, &Number::Float(f64::INFINITY)).unwrap(), 8.0);
+		let a = Vector::I16(vec![1, 2, 3]);
+		let b = Vector::I16(vec![4, -6, 3]);
+		assert_eq!(a.minkowski_distance(&b, &Number::Float(f64::INFINITY)).unwrap(), 8.0);
+		// Orders between 0 and 1 are still computed
+		assert!(a.minkowski_distance(&b, &Number::Float(0.5)).unwrap().is_finite());
 	}
 }
@@ -80,14 +80,53 @@ pub enum Distance {
 impl Distance {
 	pub(crate) fn compute(&self, v1: &Vec<Number>, v2: &Vec<Number>) -> Result<Number, Error> {
 		match self {
-			Distance::Cosine => v1.cosine_similarity(v2),
+			Distance::Cosine => Ok(Self::similarity_to_distance(v1.cosine_similarity(v2)?)),
 			Distance::Chebyshev => v1.chebyshev_distance(v2),
 			Distance::Euclidean => v1.euclidean_distance(v2),
 			Distance::Hamming => v1.hamming_distance(v2),
-			Distance::Jaccard => v1.jaccard_similarity(v2),
+			Distance::Jaccard => Ok(Self::similarity_to_distance(v1.jaccard_similarity(v2)?)),
 			Distance::Manhattan => v1.manhattan_distance(v2),
-			Distance::Minkowski(r) => v1.minkowski_distance(v2, r),
-			Distance::Pearson => v1.pearson_similarity(v2),
+			Distance::Minkowski(r) => {
+				Self::check_minkowski_order(r)?;
+				if r.to_float() == f64::INFINITY {
+					v1.chebyshev_distance(v2)
+				} else {
+					v1.minkowski_distance(v2, r)
+				}
+			}
+			Distance::Pearson => Ok(Self::similarity_to_distance(v1.pearson_similarity(v2)?)),
+		}
+	}
+
+	/// Checks that the parameters of the distance are valid.
+	pub(crate) fn validate(&self) -> Result<(), Error> {
+		if let Distance::Minkowski(order) = self {
+			Self::check_minkowski_order(order)?;
+		}
+		Ok(())
+	}
+
+	/// The order of a Minkowski distance must be strictly positive.
+	/// An infinite order is the Chebyshev distance.
+	/// Orders lower than 1 are accepted, but they don't satisfy the triangle inequality.
+	pub(crate) fn check_minkowski_order(order: &Number) -> Result<(), Error> {
+		let p = order.to_float();
+		if p.is_nan() || p <= 0.0 {
+			Err(Error::InvalidMinkowskiOrder(order.clone()))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Converts a similarity (1 means identical) into a distance (0 means identical).
+	/// An undefined similarity (NaN, e.g. a zero vector for Cosine or a constant vector for Pearson)
+	/// is considered as the farthest possible distance.
+	fn similarity_to_distance(s: Number) -> Number {
+		let s = s.to_float();
+		if s.is_nan() {
+			Number::Float(f64::INFINITY)
+		} else {
+			Number::Float(1.0 - s)
 		}
 	}
 }
@@ -31,6 +31,10 @@ impl DefineIndexStatement {
 	) -> Result<Value, Error> {
 		// Allowed to run?
 		opt.is_allowed(Action::Edit, ResourceKind::Index, &Base::Db)?;
+		// Check the index parameters
+		if let Index::MTree(p) = &self.index {
+			p.distance.validate()?;
+		}
 		// Claim transaction
 		let mut run = txn.lock().await;
 		// Clear the cache
@@ -203,3 +203,87 @@ async fn select_where_brut_force_knn() -> Result<(), Error> {
 	assert_eq!(format!("{:#}", tmp), format!("{:#}", val));
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_where_brut_force_knn_similarity() -> Result<(), Error> {
+	let sql = r"
+		CREATE pts:1 SET point = [1,2,3,4];
+		CREATE pts:2 SET point = [4,5,7,6];
+		CREATE pts:3 SET point = [8,11,10,9];
+		CREATE pts:4 SET point = [5,5,5,5];
+		SELECT id FROM pts WHERE point knn<2,JACCARD> [2,3,4,5];
+		SELECT id FROM pts WHERE point knn<2,PEARSON> [1,2,3,4];
+		SELECT id FROM pts WHERE point knn<2,COSINE> [1,2,3,4];
+		SELECT id FROM pts WHERE point knn<3,PEARSON> [1,2,3,4];
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 8);
+	//
+	for _ in 0..4 {
+		let _ = res.remove(0).result?;
+	}
+	// The most similar vectors are the nearest neighbours:
+	// - JACCARD distances: pts:1 0.4, pts:2 0.67, pts:4 0.75, pts:3 1
+	// - PEARSON distances: pts:1 0, pts:2 0.2, pts:3 0.8, pts:4 undefined
+	// - COSINE distances: pts:1 0, pts:2 0.04, pts:3 0.084, pts:4 0.087
+	for _ in 0..3 {
+		let tmp = res.remove(0).result?;
+		let val = Value::parse(
+			"[
+				{
+					id: pts:1
+				},
+				{
+					id: pts:2
+				}
+			]",
+		);
+		assert_eq!(format!("{:#}", tmp), format!("{:#}", val));
+	}
+	// The Pearson correlation of a constant vector is undefined, it is the farthest neighbour
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: pts:1
+			},
+			{
+				id: pts:2
+			},
+			{
+				id: pts:3
+			}
+		]",
+	);
+	assert_eq!(format!("{:#}", tmp), format!("{:#}", val));
+	Ok(())
+}
+
+#[tokio::test]
+async fn invalid_minkowski_order() -> Result<(), Error> {
+	let sql = r"
+		DEFINE INDEX mt_pts ON pts FIELDS point MTREE DIMENSION 4 DIST MINKOWSKI 0;
+		CREATE pts:1 SET point = [1,2,3,4];
+		SELECT id FROM pts WHERE point knn<1,MINKOWSKI 0> [2,3,4,5];
+		SELECT id FROM pts WHERE point knn<1,MINKOWSKI 3> [2,3,4,5];
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	// The index definition is rejected
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidMinkowskiOrder(_))), "found {:?}", tmp);
+	//
+	let _ = res.remove(0).result?;
+	// The brute force KNN rejects the same order
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidMinkowskiOrder(_))), "found {:?}", tmp);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:1 }]");
+	assert_eq!(format!("{:#}", tmp), format!("{:#}", val));
+	Ok(())
+}